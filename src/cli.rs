@@ -34,5 +34,66 @@ pub fn parse_args() -> clap::ArgMatches<'static> {
                 .long("no-push")
                 .help("do not push git tag to remote")
         )
+        .arg(
+            Arg::with_name("auto")
+                .long("auto")
+                .help("infer bump level from conventional commit history instead of prompting")
+        )
+        .arg(
+            Arg::with_name("sign")
+                .long("sign")
+                .conflicts_with("no-sign")
+                .help("sign the created tag (see tag.gpgSign, user.signingkey, gpg.format)")
+        )
+        .arg(
+            Arg::with_name("remote")
+                .long("remote")
+                .short("R")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .default_value("origin")
+                .help("remote to push the tag to (repeatable)")
+        )
+        .arg(
+            Arg::with_name("no-sign")
+                .long("no-sign")
+                .conflicts_with("sign")
+                .help("do not sign the created tag")
+        )
+        .arg(
+            Arg::with_name("message")
+                .long("message")
+                .short("m")
+                .takes_value(true)
+                .help("tag message (skips the generated changelog and editor)")
+        )
+        .arg(
+            Arg::with_name("no-edit")
+                .long("no-edit")
+                .help("use the generated changelog as the tag message without opening an editor")
+        )
+        .arg(
+            Arg::with_name("notify")
+                .long("notify")
+                .help("notify bump.notify.webhook / bump.notify.smtp after a successful push")
+        )
+        .arg(
+            Arg::with_name("pre")
+                .long("pre")
+                .takes_value(true)
+                .help("prerelease label (e.g. rc, beta) for the \"prerelease\" bump choice")
+        )
+        .arg(
+            Arg::with_name("allow-branch")
+                .long("allow-branch")
+                .takes_value(true)
+                .help("glob pattern the current branch must match (default: any, see bump.allowBranch)")
+        )
+        .arg(
+            Arg::with_name("allow-dirty")
+                .long("allow-dirty")
+                .help("skip the clean-worktree pre-flight guard")
+        )
         .get_matches()
 }
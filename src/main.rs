@@ -8,6 +8,23 @@ fn run() -> Result<(), anyhow::Error> {
     git_bump::Config {
         prefix: arg.value_of("prefix").map(ToOwned::to_owned),
         repository_path: arg.value_of("repo").map(ToOwned::to_owned),
+        auto: arg.is_present("auto"),
+        remote: arg
+            .values_of("remote")
+            .unwrap()
+            .map(ToOwned::to_owned)
+            .collect(),
+        sign: match (arg.is_present("sign"), arg.is_present("no-sign")) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        },
+        message: arg.value_of("message").map(ToOwned::to_owned),
+        no_edit: arg.is_present("no-edit"),
+        notify: arg.is_present("notify"),
+        pre: arg.value_of("pre").map(ToOwned::to_owned),
+        allow_branch: arg.value_of("allow-branch").map(ToOwned::to_owned),
+        allow_dirty: arg.is_present("allow-dirty"),
         ..Default::default()
     }
     .bump()